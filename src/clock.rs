@@ -404,7 +404,10 @@ impl ClocksDescriptor {
         };
 
         let periph_clocks = PeriphClocks {
+            ssp0: SSP0Clock { _private: () },
+            ssp1: SSP1Clock { _private: () },
             usart: USARTClock { _private: () },
+            i2c: I2CClock { _private: () },
             usb: USBClock { _private: () },
             syscon: Syscon { _private: () },
         };
@@ -440,10 +443,10 @@ pub struct USARTClock {
 }
 
 pub struct PeriphClocks {
-    //pub ssp0: SSP0Clock,
-    //pub ssp1: SSP1Clock,
+    pub ssp0: SSP0Clock,
+    pub ssp1: SSP1Clock,
     pub usart: USARTClock,
-    //pub i2c: I2CClock,
+    pub i2c: I2CClock,
     pub usb: USBClock,
     pub syscon: Syscon
 }
@@ -485,6 +488,100 @@ impl USARTClock {
     }
 }
 
+/// Common interface to the SSP0/SSP1 peripheral clock dividers, so that
+/// [`crate::ssp::Spi`] can be configured generically over either instance.
+pub trait SspClock {
+    fn configure(&mut self, clocks: Clocks, freq: Hertz);
+    fn disable(&mut self);
+}
+
+pub struct I2CClock {
+    _private: ()
+}
+
+impl I2CClock {
+    // The I2C block, unlike USART/SSP, has no dedicated clock divider: it
+    // runs directly off the system clock, with the bus speed set entirely
+    // by the SCLH/SCLL duty registers (see `crate::i2c`).
+    #[inline]
+    pub fn configure(&mut self) {
+        let syscon = SYSCON_IMPL::ptr();
+        unsafe {
+            cortex_m::interrupt::free(|_| {
+                (*syscon).sysahbclkctrl.modify(|_, w| w.i2c().enabled());
+            });
+        }
+    }
+
+    #[inline]
+    pub fn disable(&mut self) {
+        let syscon = SYSCON_IMPL::ptr();
+        unsafe {
+            cortex_m::interrupt::free(|_| {
+                (*syscon).sysahbclkctrl.modify(|_, w| w.i2c().disabled());
+            });
+        }
+    }
+}
+
+pub struct SSP0Clock {
+    _private: ()
+}
+
+impl SspClock for SSP0Clock {
+    #[inline]
+    fn configure(&mut self, clocks: Clocks, freq: Hertz) {
+        let syscon = SYSCON_IMPL::ptr();
+        let div = (clocks.main_clock_freq().0 / freq.0) as u8;
+        unsafe {
+            cortex_m::interrupt::free(|_| {
+                (*syscon).sysahbclkctrl.modify(|_, w| w.ssp0().enabled());
+            });
+            (*syscon).ssp0clkdiv.write(|v| v.div().bits(div));
+        }
+    }
+
+    #[inline]
+    fn disable(&mut self) {
+        let syscon = SYSCON_IMPL::ptr();
+        unsafe {
+            cortex_m::interrupt::free(|_| {
+                (*syscon).ssp0clkdiv.write(|w| w.div().bits(0));
+                (*syscon).sysahbclkctrl.modify(|_, w| w.ssp0().disabled());
+            });
+        }
+    }
+}
+
+pub struct SSP1Clock {
+    _private: ()
+}
+
+impl SspClock for SSP1Clock {
+    #[inline]
+    fn configure(&mut self, clocks: Clocks, freq: Hertz) {
+        let syscon = SYSCON_IMPL::ptr();
+        let div = (clocks.main_clock_freq().0 / freq.0) as u8;
+        unsafe {
+            cortex_m::interrupt::free(|_| {
+                (*syscon).sysahbclkctrl.modify(|_, w| w.ssp1().enabled());
+            });
+            (*syscon).ssp1clkdiv.write(|v| v.div().bits(div));
+        }
+    }
+
+    #[inline]
+    fn disable(&mut self) {
+        let syscon = SYSCON_IMPL::ptr();
+        unsafe {
+            cortex_m::interrupt::free(|_| {
+                (*syscon).ssp1clkdiv.write(|w| w.div().bits(0));
+                (*syscon).sysahbclkctrl.modify(|_, w| w.ssp1().disabled());
+            });
+        }
+    }
+}
+
 pub struct USBClock {
     _private: ()
 }
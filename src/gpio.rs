@@ -4,13 +4,24 @@
 // BODY: This needs examples and high-level explanations, ideally with links
 // BODY: to the reference manual.
 
+use core::convert::Infallible;
 use core::marker::PhantomData;
 
+use embedded_hal::digital::v2::{InputPin, OutputPin, StatefulOutputPin, ToggleableOutputPin};
+
 pub trait GpioExt {
     type Parts;
     fn split(self) -> Self::Parts;
 }
 
+/// Identifies which `GPIO_PORT`/bit a pin lives at, regardless of its
+/// current `FUNC` type state. Used by peripherals that need to refer to an
+/// arbitrary GPIO pin at runtime, such as [`crate::pint`].
+pub trait PinNumber {
+    const PORT: u8;
+    const PIN: u8;
+}
+
 pub struct Floating;
 pub struct PullDown;
 pub struct PullUp;
@@ -19,6 +30,22 @@ pub struct Input<MODE> {
     _mode: PhantomData<MODE>
 }
 
+/// Push-pull output mode (type state).
+pub struct PushPull;
+/// Open-drain output mode (type state).
+///
+/// The LPC11Uxx GPIO pins (other than the dedicated I2C pins) have no
+/// hardware open-drain mode, so this is emulated in software: driving the
+/// pin low sets it as an output and clears it, while driving it high simply
+/// switches it back to a floating input and lets an external pull-up (or
+/// the IOCON pull-up) bring the line high.
+pub struct OpenDrain;
+
+/// An output pin configured in either push-pull or open-drain mode.
+pub struct Output<MODE> {
+    _mode: PhantomData<MODE>
+}
+
 // SSP
 pub struct SCK0;
 pub struct SSEL0;
@@ -43,6 +70,273 @@ pub struct DSR;
 pub struct DCD;
 pub struct RI;
 
+/// A type-erased GPIO pin: its port and bit index are tracked at runtime
+/// rather than in the type, so e.g. an `[ErasedPin<Output<PushPull>>; 4]`
+/// can mix pins from both `GPIO_PORT` ports. Obtained via `downgrade()` on
+/// a configured `Output`/`Input` pin.
+pub struct ErasedPin<MODE> {
+    port: u8,
+    pin: u8,
+    _mode: PhantomData<MODE>,
+}
+
+impl<MODE> ErasedPin<MODE> {
+    fn new(port: u8, pin: u8) -> Self {
+        ErasedPin { port, pin, _mode: PhantomData }
+    }
+
+    fn mask(&self) -> u32 {
+        1 << self.pin
+    }
+}
+
+impl OutputPin for ErasedPin<Output<PushPull>> {
+    type Error = Infallible;
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        match self.port {
+            0 => gpio_port.set0.write(|w| unsafe { w.bits(self.mask()) }),
+            _ => gpio_port.set1.write(|w| unsafe { w.bits(self.mask()) }),
+        }
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        match self.port {
+            0 => gpio_port.clr0.write(|w| unsafe { w.bits(self.mask()) }),
+            _ => gpio_port.clr1.write(|w| unsafe { w.bits(self.mask()) }),
+        }
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for ErasedPin<Output<PushPull>> {
+    fn is_set_high(&self) -> Result<bool, Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        let bits = match self.port {
+            0 => gpio_port.pin0.read().bits(),
+            _ => gpio_port.pin1.read().bits(),
+        };
+        Ok(bits & self.mask() != 0)
+    }
+
+    fn is_set_low(&self) -> Result<bool, Self::Error> {
+        self.is_set_high().map(|b| !b)
+    }
+}
+
+impl ToggleableOutputPin for ErasedPin<Output<PushPull>> {
+    type Error = Infallible;
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        if self.is_set_high()? {
+            self.set_low()
+        } else {
+            self.set_high()
+        }
+    }
+}
+
+impl OutputPin for ErasedPin<Output<OpenDrain>> {
+    type Error = Infallible;
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        match self.port {
+            0 => gpio_port.dir0.modify(|r, w| unsafe { w.bits(r.bits() & !self.mask()) }),
+            _ => gpio_port.dir1.modify(|r, w| unsafe { w.bits(r.bits() & !self.mask()) }),
+        }
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        match self.port {
+            0 => {
+                gpio_port.clr0.write(|w| unsafe { w.bits(self.mask()) });
+                gpio_port.dir0.modify(|r, w| unsafe { w.bits(r.bits() | self.mask()) });
+            }
+            _ => {
+                gpio_port.clr1.write(|w| unsafe { w.bits(self.mask()) });
+                gpio_port.dir1.modify(|r, w| unsafe { w.bits(r.bits() | self.mask()) });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for ErasedPin<Output<OpenDrain>> {
+    // Tracks the commanded state via `DIR` rather than sampling the pad:
+    // a released (floating) pin with no pull-up present can read back low,
+    // which would make `toggle()` think it's already low and never drive
+    // it, getting stuck.
+    fn is_set_high(&self) -> Result<bool, Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        let dir = match self.port {
+            0 => gpio_port.dir0.read().bits(),
+            _ => gpio_port.dir1.read().bits(),
+        };
+        // DIR clear (input/released) is the commanded "high" state.
+        Ok(dir & self.mask() == 0)
+    }
+
+    fn is_set_low(&self) -> Result<bool, Self::Error> {
+        self.is_set_high().map(|b| !b)
+    }
+}
+
+impl ToggleableOutputPin for ErasedPin<Output<OpenDrain>> {
+    type Error = Infallible;
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        if self.is_set_high()? {
+            self.set_low()
+        } else {
+            self.set_high()
+        }
+    }
+}
+
+impl<MODE> InputPin for ErasedPin<Input<MODE>> {
+    type Error = Infallible;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+        let bits = match self.port {
+            0 => gpio_port.pin0.read().bits(),
+            _ => gpio_port.pin1.read().bits(),
+        };
+        Ok(bits & self.mask() != 0)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.is_high().map(|b| !b)
+    }
+}
+
+// Generates a `PartiallyErasedPin<MODE>` for a single port: it erases the
+// pin index but, unlike `ErasedPin`, keeps the port fixed at compile time
+// (it lives in, and is only constructible from, that port's module), so it
+// can use that port's registers directly instead of matching on a runtime
+// port number.
+macro_rules! gpio_erased_port {
+    ($dir:ident, $set:ident, $clr:ident, $pin_reg:ident) => {
+        pub struct PartiallyErasedPin<MODE> {
+            pin: u8,
+            _mode: PhantomData<MODE>,
+        }
+
+        impl<MODE> PartiallyErasedPin<MODE> {
+            fn new(pin: u8) -> Self {
+                PartiallyErasedPin { pin, _mode: PhantomData }
+            }
+
+            fn mask(&self) -> u32 {
+                1 << self.pin
+            }
+        }
+
+        impl OutputPin for PartiallyErasedPin<Output<PushPull>> {
+            type Error = Infallible;
+
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$set.write(|w| unsafe { w.bits(self.mask()) });
+                Ok(())
+            }
+
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$clr.write(|w| unsafe { w.bits(self.mask()) });
+                Ok(())
+            }
+        }
+
+        impl StatefulOutputPin for PartiallyErasedPin<Output<PushPull>> {
+            fn is_set_high(&self) -> Result<bool, Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                Ok(gpio_port.$pin_reg.read().bits() & self.mask() != 0)
+            }
+
+            fn is_set_low(&self) -> Result<bool, Self::Error> {
+                self.is_set_high().map(|b| !b)
+            }
+        }
+
+        impl ToggleableOutputPin for PartiallyErasedPin<Output<PushPull>> {
+            type Error = Infallible;
+
+            fn toggle(&mut self) -> Result<(), Self::Error> {
+                if self.is_set_high()? {
+                    self.set_low()
+                } else {
+                    self.set_high()
+                }
+            }
+        }
+
+        impl OutputPin for PartiallyErasedPin<Output<OpenDrain>> {
+            type Error = Infallible;
+
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() & !self.mask()) });
+                Ok(())
+            }
+
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$clr.write(|w| unsafe { w.bits(self.mask()) });
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() | self.mask()) });
+                Ok(())
+            }
+        }
+
+        impl StatefulOutputPin for PartiallyErasedPin<Output<OpenDrain>> {
+            // Tracks the commanded state via `DIR` rather than sampling the
+            // pad: a released (floating) pin with no pull-up present can
+            // read back low, which would make `toggle()` think it's already
+            // low and never drive it, getting stuck.
+            fn is_set_high(&self) -> Result<bool, Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                // DIR clear (input/released) is the commanded "high" state.
+                Ok(gpio_port.$dir.read().bits() & self.mask() == 0)
+            }
+
+            fn is_set_low(&self) -> Result<bool, Self::Error> {
+                self.is_set_high().map(|b| !b)
+            }
+        }
+
+        impl ToggleableOutputPin for PartiallyErasedPin<Output<OpenDrain>> {
+            type Error = Infallible;
+
+            fn toggle(&mut self) -> Result<(), Self::Error> {
+                if self.is_set_high()? {
+                    self.set_low()
+                } else {
+                    self.set_high()
+                }
+            }
+        }
+
+        impl<MODE> InputPin for PartiallyErasedPin<Input<MODE>> {
+            type Error = Infallible;
+
+            fn is_high(&self) -> Result<bool, Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                Ok(gpio_port.$pin_reg.read().bits() & self.mask() != 0)
+            }
+
+            fn is_low(&self) -> Result<bool, Self::Error> {
+                self.is_high().map(|b| !b)
+            }
+        }
+    };
+}
+
 macro_rules! gpio_func {
    ($PXi:ident, $iocon_pio_name:ident, $into_func:ident -> $FUNC:ty { $iocon_func_name:ident }) => {
         pub fn $into_func(self) -> $PXi<$FUNC> {
@@ -64,9 +358,221 @@ macro_rules! gpio_func {
    };
 }
 
+// Generates the `into_*_output`/`into_*_input` conversions and the
+// `embedded-hal` digital trait impls for a single GPIO pin. `$dir`/`$set`/
+// `$clr`/`$pin_reg` are the GPIO_PORT registers for the port this pin belongs
+// to, and `$bit` is this pin's bit index within that port.
+macro_rules! gpio_digital {
+    ($PXi:ident, $iocon_pio_name:ident, $portnum:expr, $dir:ident, $set:ident, $clr:ident, $pin_reg:ident, $bit:expr) => {
+        impl<FUNC> PinNumber for $PXi<FUNC> {
+            const PORT: u8 = $portnum;
+            const PIN: u8 = $bit;
+        }
+
+        impl<FUNC> $PXi<FUNC> {
+            pub fn into_push_pull_output(self) -> $PXi<Output<PushPull>> {
+                let iocon = unsafe { &*lpc11uxx::IOCON::ptr() };
+                iocon.$iocon_pio_name.write(|v| v.func().gpio());
+
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() | (1 << $bit)) });
+
+                $PXi { _mode: PhantomData }
+            }
+
+            pub fn into_open_drain_output(self) -> $PXi<Output<OpenDrain>> {
+                let iocon = unsafe { &*lpc11uxx::IOCON::ptr() };
+                iocon.$iocon_pio_name.write(|v| v.func().gpio());
+
+                // Start out released (floating high), as a real open-drain
+                // output would be after reset.
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() & !(1 << $bit)) });
+
+                $PXi { _mode: PhantomData }
+            }
+
+            pub fn into_floating_input(self) -> $PXi<Input<Floating>> {
+                let iocon = unsafe { &*lpc11uxx::IOCON::ptr() };
+                iocon.$iocon_pio_name.write(|v| v
+                    .func().gpio()
+                    .mode().inactive());
+
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() & !(1 << $bit)) });
+
+                $PXi { _mode: PhantomData }
+            }
+
+            pub fn into_pull_up_input(self) -> $PXi<Input<PullUp>> {
+                let iocon = unsafe { &*lpc11uxx::IOCON::ptr() };
+                iocon.$iocon_pio_name.write(|v| v
+                    .func().gpio()
+                    .mode().pull_up());
+
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() & !(1 << $bit)) });
+
+                $PXi { _mode: PhantomData }
+            }
+
+            pub fn into_pull_down_input(self) -> $PXi<Input<PullDown>> {
+                let iocon = unsafe { &*lpc11uxx::IOCON::ptr() };
+                iocon.$iocon_pio_name.write(|v| v
+                    .func().gpio()
+                    .mode().pull_down());
+
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() & !(1 << $bit)) });
+
+                $PXi { _mode: PhantomData }
+            }
+        }
+
+        impl OutputPin for $PXi<Output<PushPull>> {
+            type Error = Infallible;
+
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$set.write(|w| unsafe { w.bits(1 << $bit) });
+                Ok(())
+            }
+
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$clr.write(|w| unsafe { w.bits(1 << $bit) });
+                Ok(())
+            }
+        }
+
+        impl StatefulOutputPin for $PXi<Output<PushPull>> {
+            fn is_set_high(&self) -> Result<bool, Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                Ok(gpio_port.$pin_reg.read().bits() & (1 << $bit) != 0)
+            }
+
+            fn is_set_low(&self) -> Result<bool, Self::Error> {
+                self.is_set_high().map(|b| !b)
+            }
+        }
+
+        impl ToggleableOutputPin for $PXi<Output<PushPull>> {
+            type Error = Infallible;
+
+            fn toggle(&mut self) -> Result<(), Self::Error> {
+                if self.is_set_high()? {
+                    self.set_low()
+                } else {
+                    self.set_high()
+                }
+            }
+        }
+
+        impl $PXi<Output<PushPull>> {
+            /// Erases the pin number *and* port from the type, so it can be
+            /// stored alongside pins from the other port.
+            pub fn downgrade(self) -> ErasedPin<Output<PushPull>> {
+                ErasedPin::new($portnum, $bit)
+            }
+
+            /// Erases the pin number from the type, keeping the port fixed.
+            pub fn downgrade_to_port(self) -> PartiallyErasedPin<Output<PushPull>> {
+                PartiallyErasedPin::new($bit)
+            }
+        }
+
+        impl OutputPin for $PXi<Output<OpenDrain>> {
+            type Error = Infallible;
+
+            // Releasing the line: switch back to input so the external (or
+            // IOCON) pull-up can bring it high.
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() & !(1 << $bit)) });
+                Ok(())
+            }
+
+            // Driving the line low: switch to output and clear it.
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                gpio_port.$clr.write(|w| unsafe { w.bits(1 << $bit) });
+                gpio_port.$dir.modify(|r, w| unsafe { w.bits(r.bits() | (1 << $bit)) });
+                Ok(())
+            }
+        }
+
+        impl StatefulOutputPin for $PXi<Output<OpenDrain>> {
+            // Tracks the commanded state via `DIR` rather than sampling the
+            // pad: a released (floating) pin with no pull-up present can
+            // read back low, which would make `toggle()` think it's already
+            // low and never drive it, getting stuck.
+            fn is_set_high(&self) -> Result<bool, Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                // DIR clear (input/released) is the commanded "high" state.
+                Ok(gpio_port.$dir.read().bits() & (1 << $bit) == 0)
+            }
+
+            fn is_set_low(&self) -> Result<bool, Self::Error> {
+                self.is_set_high().map(|b| !b)
+            }
+        }
+
+        impl ToggleableOutputPin for $PXi<Output<OpenDrain>> {
+            type Error = Infallible;
+
+            fn toggle(&mut self) -> Result<(), Self::Error> {
+                if self.is_set_high()? {
+                    self.set_low()
+                } else {
+                    self.set_high()
+                }
+            }
+        }
+
+        impl $PXi<Output<OpenDrain>> {
+            /// Erases the pin number *and* port from the type, so it can be
+            /// stored alongside pins from the other port.
+            pub fn downgrade(self) -> ErasedPin<Output<OpenDrain>> {
+                ErasedPin::new($portnum, $bit)
+            }
+
+            /// Erases the pin number from the type, keeping the port fixed.
+            pub fn downgrade_to_port(self) -> PartiallyErasedPin<Output<OpenDrain>> {
+                PartiallyErasedPin::new($bit)
+            }
+        }
+
+        impl<MODE> InputPin for $PXi<Input<MODE>> {
+            type Error = Infallible;
+
+            fn is_high(&self) -> Result<bool, Self::Error> {
+                let gpio_port = unsafe { &*lpc11uxx::GPIO_PORT::ptr() };
+                Ok(gpio_port.$pin_reg.read().bits() & (1 << $bit) != 0)
+            }
+
+            fn is_low(&self) -> Result<bool, Self::Error> {
+                self.is_high().map(|b| !b)
+            }
+        }
+
+        impl<MODE> $PXi<Input<MODE>> {
+            /// Erases the pin number *and* port from the type, so it can be
+            /// stored alongside pins from the other port.
+            pub fn downgrade(self) -> ErasedPin<Input<MODE>> {
+                ErasedPin::new($portnum, $bit)
+            }
+
+            /// Erases the pin number from the type, keeping the port fixed.
+            pub fn downgrade_to_port(self) -> PartiallyErasedPin<Input<MODE>> {
+                PartiallyErasedPin::new($bit)
+            }
+        }
+    };
+}
+
 macro_rules! gpio {
-    ($($port:ident: [
-        $($PXi:ident: ($pxi:ident, $iocon_pio_name:ident, $DEFAULT_FUNC:ty, [
+    ($($port:ident($portnum:expr, $dir:ident, $set:ident, $clr:ident, $pin_reg:ident): [
+        $($PXi:ident($bit:expr): ($pxi:ident, $iocon_pio_name:ident, $DEFAULT_FUNC:ty, [
             $($into_func:ident -> $FUNC:ty { $($tt:tt)* }),*
         ]),)+
     ]),*) => {
@@ -97,81 +603,84 @@ macro_rules! gpio {
                 $(gpio_func!($PXi, $iocon_pio_name, $into_func -> $FUNC { $($tt)* });)*
             }
 
+            gpio_digital!($PXi, $iocon_pio_name, $portnum, $dir, $set, $clr, $pin_reg, $bit);
             )*
+
+            gpio_erased_port!($dir, $set, $clr, $pin_reg);
         }
         )*
     }
 }
 
 gpio! {
-    gpio0: [
-        Pio2: (gpio0_pio2, pio0_2, Input<Floating>, [
+    gpio0(0, dir0, set0, clr0, pin0): [
+        Pio2(2): (gpio0_pio2, pio0_2, Input<Floating>, [
             into_ssel0 -> SSEL0 { ssel0, pull_up }
         ]),
-        Pio4: (gpio0_pio4, pio0_4, Input<Floating>, [
+        Pio4(4): (gpio0_pio4, pio0_4, Input<Floating>, [
             into_scl -> SCL { i2c_scl }
         ]),
-        Pio5: (gpio0_pio5, pio0_5, Input<Floating>, [
+        Pio5(5): (gpio0_pio5, pio0_5, Input<Floating>, [
             into_sda -> SDA { i2c_sda }
         ]),
-        Pio6: (gpio0_pio6, pio0_6, Input<Floating>, [
+        Pio6(6): (gpio0_pio6, pio0_6, Input<Floating>, [
             into_sck0 -> SCK0 { sck0, pull_up }
         ]),
-        Pio8: (gpio0_pio8, pio0_8, Input<Floating>, [
+        Pio8(8): (gpio0_pio8, pio0_8, Input<Floating>, [
             into_miso0 -> MISO0 { miso0, pull_up }
         ]),
-        Pio9: (gpio0_pio9, pio0_9, Input<Floating>, [
+        Pio9(9): (gpio0_pio9, pio0_9, Input<Floating>, [
             into_mosi0 -> MOSI0 { mosi0, pull_up }
         ]),
-        Pio10: (gpio0_pio10, swclk_pio0_10, Input<Floating>, [
+        Pio10(10): (gpio0_pio10, swclk_pio0_10, Input<Floating>, [
             into_sck0 -> SCK0 { sck0, pull_up }
         ]),
-        Pio18: (gpio0_pio18, pio0_18, Input<Floating>, [
+        Pio18(18): (gpio0_pio18, pio0_18, Input<Floating>, [
             into_rxd -> RXD { rxd }
         ]),
-        Pio19: (gpio0_pio19, pio0_19, Input<Floating>, [
+        Pio19(19): (gpio0_pio19, pio0_19, Input<Floating>, [
             into_txd -> TXD { txd }
         ]),
-        Pio21: (gpio0_pio21, pio0_21, Input<Floating>, [
+        Pio21(21): (gpio0_pio21, pio0_21, Input<Floating>, [
             into_mosi1 -> MOSI1 { mosi1, pull_up }
         ]),
-        Pio22: (gpio0_pio22, pio0_22, Input<Floating>, [
+        Pio22(22): (gpio0_pio22, pio0_22, Input<Floating>, [
             into_miso1 -> MISO1 { miso1, pull_up }
         ]),
     ],
-    gpio1: [
-        Pio13: (gpio1_pio13, pio1_13, Input<Floating>, [
+    gpio1(1, dir1, set1, clr1, pin1): [
+        Pio13(13): (gpio1_pio13, pio1_13, Input<Floating>, [
             into_txd -> TXD { txd }
         ]),
-        Pio14: (gpio1_pio14, pio1_14, Input<Floating>, [
+        Pio14(14): (gpio1_pio14, pio1_14, Input<Floating>, [
             into_rxd -> RXD { rxd }
         ]),
-        Pio15: (gpio1_pio15, pio1_15, Input<Floating>, [
+        Pio15(15): (gpio1_pio15, pio1_15, Input<Floating>, [
             into_sck1 -> SCK1 { sck1, pull_up }
         ]),
-        Pio19: (gpio1_pio19, pio1_19, Input<Floating>, [
+        Pio19(19): (gpio1_pio19, pio1_19, Input<Floating>, [
             into_ssel1 -> SSEL1 { ssel1, pull_up }
         ]),
-        Pio20: (gpio1_pio20, pio1_20, Input<Floating>, [
+        Pio20(20): (gpio1_pio20, pio1_20, Input<Floating>, [
             into_sck1 -> SCK1 { sck1, pull_up }
         ]),
-        Pio21: (gpio1_pio21, pio1_21, Input<Floating>, [
+        Pio21(21): (gpio1_pio21, pio1_21, Input<Floating>, [
             into_miso1 -> MISO1 { miso1, pull_up }
         ]),
-        Pio22: (gpio1_pio22, pio1_22, Input<Floating>, [
+        Pio22(22): (gpio1_pio22, pio1_22, Input<Floating>, [
             into_mosi1 -> MOSI1 { mosi1, pull_up }
         ]),
-        Pio23: (gpio1_pio23, pio1_23, Input<Floating>, [
+        Pio23(23): (gpio1_pio23, pio1_23, Input<Floating>, [
             into_ssel1 -> SSEL1 { ssel1, pull_up }
         ]),
-        Pio26: (gpio1_pio26, pio1_26, Input<Floating>, [
+        Pio26(26): (gpio1_pio26, pio1_26, Input<Floating>, [
             into_rxd -> RXD { rxd }
         ]),
-        Pio27: (gpio1_pio27, pio1_27, Input<Floating>, [
+        Pio27(27): (gpio1_pio27, pio1_27, Input<Floating>, [
             into_txd -> TXD { txd }
         ]),
-        Pio29: (gpio1_pio29, pio1_29, Input<Floating>, [
+        Pio29(29): (gpio1_pio29, pio1_29, Input<Floating>, [
             into_sck0 -> SCK0 { sck0, pull_up }
         ]),
     ]
-}
\ No newline at end of file
+}
@@ -0,0 +1,168 @@
+//! I2C Configuration
+
+use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
+
+use crate::clock::{Clocks, Hertz, I2CClock};
+use crate::gpio::gpio0::{Pio4, Pio5};
+use crate::gpio::{SCL, SDA};
+
+pub trait Pins {}
+
+impl Pins for (Pio4<SCL>, Pio5<SDA>) {}
+
+pub struct I2c<PINS> {
+    i2c: lpc11uxx::I2C,
+    _pins: PINS,
+}
+
+// TODO: BITFLAGS
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+impl Error {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// The I2C bus arbitration was lost to another master.
+    ArbitrationLoss,
+    /// The addressed slave did not acknowledge the address.
+    AddressNack,
+    /// The addressed slave did not acknowledge a written data byte.
+    DataNack,
+    /// The I2C state machine ended up in a state this driver does not
+    /// support recovering from (e.g. a bus error).
+    Other(u8),
+}
+
+impl<PINS: Pins> I2c<PINS> {
+    pub fn new(i2c: lpc11uxx::I2C, pins: PINS, freq: Hertz, clocks: Clocks, mut i2c_clock: I2CClock) -> Self {
+        i2c_clock.configure();
+
+        // The I2C clock runs off the (undivided) system clock; split it
+        // evenly between the high and low half of the SCL duty cycle.
+        let half_period = (clocks.main_clock_freq().0 / freq.0 / 2) as u16;
+
+        i2c.sclh.write(|v| unsafe { v.bits(half_period) });
+        i2c.scll.write(|v| unsafe { v.bits(half_period) });
+
+        i2c.conclr.write(|v| v
+            .i2ence_clr().set_bit()
+            .staclr_clr().set_bit()
+            .siclr_clr().set_bit()
+            .aaclr_clr().set_bit());
+        i2c.conset.write(|v| v.i2en_set().set_bit());
+
+        I2c { i2c, _pins: pins }
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        self.i2c.conset.write(|v| v.sta_set().set_bit());
+        // SI is still set after a previous transfer phase (as in
+        // `write_read`); the repeated START only goes out once it's cleared.
+        self.i2c.conclr.write(|v| v.siclr_clr().set_bit());
+        self.wait_for_si()?;
+        self.i2c.conclr.write(|v| v.staclr_clr().set_bit());
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        self.i2c.conset.write(|v| v.sto_set().set_bit());
+        self.i2c.conclr.write(|v| v.siclr_clr().set_bit());
+        while self.i2c.conset.read().sto_set().bit_is_set() {}
+    }
+
+    fn wait_for_si(&mut self) -> Result<(), Error> {
+        while self.i2c.conset.read().si_set().bit_is_clear() {}
+        self.check_status()
+    }
+
+    fn check_status(&mut self) -> Result<(), Error> {
+        let status = self.i2c.stat.read().status().bits();
+        match status {
+            // START/repeated-START sent, SLA+W/SLA+R ack'd, data byte
+            // sent/received and ack'd: all fine, keep going. 0x58 (data byte
+            // received, NACK returned) is also fine: `read_bytes` always
+            // NACKs the last byte of a read by design, so this is the
+            // expected status on the final `wait_for_si` of every read.
+            0x08 | 0x10 | 0x18 | 0x28 | 0x40 | 0x50 | 0x58 => Ok(()),
+            0x38 => Err(Error { kind: ErrorKind::ArbitrationLoss }),
+            0x20 | 0x48 => Err(Error { kind: ErrorKind::AddressNack }),
+            0x30 => Err(Error { kind: ErrorKind::DataNack }),
+            other => Err(Error { kind: ErrorKind::Other(other) }),
+        }
+    }
+
+    fn write_bytes(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Error> {
+        self.start()?;
+
+        self.i2c.dat.write(|v| unsafe { v.data().bits(addr << 1) });
+        self.i2c.conclr.write(|v| v.siclr_clr().set_bit());
+        self.wait_for_si()?;
+
+        for &byte in bytes {
+            self.i2c.dat.write(|v| unsafe { v.data().bits(byte) });
+            self.i2c.conclr.write(|v| v.siclr_clr().set_bit());
+            self.wait_for_si()?;
+        }
+
+        Ok(())
+    }
+
+    fn read_bytes(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        self.start()?;
+
+        self.i2c.dat.write(|v| unsafe { v.data().bits((addr << 1) | 1) });
+        self.i2c.conclr.write(|v| v.siclr_clr().set_bit());
+        self.wait_for_si()?;
+
+        for (i, slot) in buffer.iter_mut().enumerate() {
+            // NACK the last byte, ACK every other one.
+            if i + 1 == buffer.len() {
+                self.i2c.conclr.write(|v| v.aaclr_clr().set_bit());
+            } else {
+                self.i2c.conset.write(|v| v.aa_set().set_bit());
+            }
+            self.i2c.conclr.write(|v| v.siclr_clr().set_bit());
+            self.wait_for_si()?;
+            *slot = self.i2c.dat.read().data().bits();
+        }
+
+        Ok(())
+    }
+}
+
+impl<PINS: Pins> Write for I2c<PINS> {
+    type Error = Error;
+
+    fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Error> {
+        let result = self.write_bytes(addr, bytes);
+        self.stop();
+        result
+    }
+}
+
+impl<PINS: Pins> Read for I2c<PINS> {
+    type Error = Error;
+
+    fn read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        let result = self.read_bytes(addr, buffer);
+        self.stop();
+        result
+    }
+}
+
+impl<PINS: Pins> WriteRead for I2c<PINS> {
+    type Error = Error;
+
+    fn write_read(&mut self, addr: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+        let result = self.write_bytes(addr, bytes).and_then(|()| self.read_bytes(addr, buffer));
+        self.stop();
+        result
+    }
+}
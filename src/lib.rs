@@ -11,5 +11,7 @@ mod const_shenanigans;
 pub mod clock;
 pub mod delay;
 pub mod gpio;
+pub mod i2c;
+pub mod pint;
 pub mod serial;
-//pub mod spi;
\ No newline at end of file
+pub mod ssp;
\ No newline at end of file
@@ -0,0 +1,161 @@
+//! Pin Interrupt (PINT) configuration
+//!
+//! The LPC11Uxx can route up to 8 GPIO pins to dedicated NVIC interrupt
+//! lines (`PIN_INT0`..`PIN_INT7`) through the Pin Interrupt block, each
+//! independently configurable as edge- or level-triggered. This is the
+//! rough equivalent of the `exti` module found in other Cortex-M HALs.
+//!
+//! The Group Interrupt (GINT) block is not covered here yet.
+
+use crate::gpio::PinNumber;
+
+/// One of the 8 Pin Interrupt channels (`PINTSEL0`..`PINTSEL7`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    Channel0,
+    Channel1,
+    Channel2,
+    Channel3,
+    Channel4,
+    Channel5,
+    Channel6,
+    Channel7,
+}
+
+impl Channel {
+    const ALL: [Channel; 8] = [
+        Channel::Channel0, Channel::Channel1, Channel::Channel2, Channel::Channel3,
+        Channel::Channel4, Channel::Channel5, Channel::Channel6, Channel::Channel7,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|c| *c == self).unwrap()
+    }
+
+    fn mask(self) -> u32 {
+        1 << self.index()
+    }
+}
+
+/// Edge-triggered interrupt kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Rising,
+    Falling,
+    Both,
+}
+
+/// Level-triggered interrupt kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Low,
+    High,
+}
+
+/// The condition a [`Pint`] channel should fire an interrupt on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Edge(Edge),
+    Level(Level),
+}
+
+/// Owns the LPC11Uxx Pin Interrupt (PINT) block. The Group Interrupt (GINT)
+/// blocks are not owned or configured by this type.
+pub struct Pint {
+    pint: lpc11uxx::PIN_INT,
+    // Tracks which channels are currently configured level-sensitive (ISEL),
+    // since writing `IST` for those channels doesn't clear the pending
+    // condition — it toggles the active-level bit in `IENF` instead.
+    level_mask: u32,
+}
+
+impl Pint {
+    pub fn new(pint: lpc11uxx::PIN_INT) -> Self {
+        Pint { pint, level_mask: 0 }
+    }
+
+    /// Routes `pin` to `channel` via the SYSCON `PINTSEL` registers.
+    ///
+    /// `pin` must first have been configured as a GPIO input (see
+    /// [`crate::gpio`]); its port/bit are read off its [`PinNumber`] impl.
+    /// The SYSCON `PINTSEL` encoding numbers pins as `port * 24 + bit`.
+    pub fn bind<P: PinNumber>(&mut self, channel: Channel, _pin: &P) {
+        let intpin = P::PORT * 24 + P::PIN;
+        let syscon = unsafe { &*lpc11uxx::SYSCON::ptr() };
+        unsafe {
+            syscon.pintsel[channel.index()].write(|v| v.intpin().bits(intpin));
+        }
+    }
+
+    /// Programs the PINT ISEL/IENR/IENF/SIENR/SIENF registers so that
+    /// `channel` raises an interrupt on `trigger`.
+    pub fn enable_interrupt(&mut self, channel: Channel, trigger: Trigger) {
+        let mask = channel.mask();
+
+        match trigger {
+            Trigger::Edge(edge) => {
+                // ISEL=0 selects edge-sensitive mode for this channel.
+                self.pint.isel.modify(|r, w| unsafe { w.bits(r.bits() & !mask) });
+                self.level_mask &= !mask;
+
+                match edge {
+                    Edge::Rising => {
+                        self.pint.ienr.modify(|r, w| unsafe { w.bits(r.bits() | mask) });
+                        self.pint.ienf.modify(|r, w| unsafe { w.bits(r.bits() & !mask) });
+                    }
+                    Edge::Falling => {
+                        self.pint.ienr.modify(|r, w| unsafe { w.bits(r.bits() & !mask) });
+                        self.pint.ienf.modify(|r, w| unsafe { w.bits(r.bits() | mask) });
+                    }
+                    Edge::Both => {
+                        self.pint.ienr.modify(|r, w| unsafe { w.bits(r.bits() | mask) });
+                        self.pint.ienf.modify(|r, w| unsafe { w.bits(r.bits() | mask) });
+                    }
+                }
+            }
+            Trigger::Level(level) => {
+                // ISEL=1 selects level-sensitive mode for this channel.
+                self.pint.isel.modify(|r, w| unsafe { w.bits(r.bits() | mask) });
+                self.pint.ienr.modify(|r, w| unsafe { w.bits(r.bits() | mask) });
+                self.level_mask |= mask;
+
+                match level {
+                    // IENF doubles as the active-level select (APL) in
+                    // level-sensitive mode: 0 = active low, 1 = active high.
+                    Level::Low => self.pint.ienf.modify(|r, w| unsafe { w.bits(r.bits() & !mask) }),
+                    Level::High => self.pint.ienf.modify(|r, w| unsafe { w.bits(r.bits() | mask) }),
+                }
+            }
+        }
+    }
+
+    pub fn disable_interrupt(&mut self, channel: Channel) {
+        let mask = channel.mask();
+        self.pint.cienr.write(|w| unsafe { w.bits(mask) });
+        self.pint.cienf.write(|w| unsafe { w.bits(mask) });
+        self.level_mask &= !mask;
+    }
+
+    /// Whether `channel` currently has a pending interrupt (`IST`).
+    pub fn is_interrupt_pending(&self, channel: Channel) -> bool {
+        self.pint.ist.read().bits() & channel.mask() != 0
+    }
+
+    /// Clears a pending interrupt on `channel` (`RISE`/`FALL`, plus `IST`
+    /// for edge-triggered channels).
+    ///
+    /// For level-triggered channels, `IST` is left untouched: writing it in
+    /// level-sensitive mode doesn't clear the pending condition, it toggles
+    /// the active-level bit in `IENF` (see `enable_interrupt`'s `Level`
+    /// handling above), so doing so here would silently flip the configured
+    /// polarity every time this is called. A level channel's interrupt
+    /// clears itself once the pin leaves the active level.
+    pub fn clear_interrupt_pending(&mut self, channel: Channel) {
+        let mask = channel.mask();
+        self.pint.rise.write(|w| unsafe { w.bits(mask) });
+        self.pint.fall.write(|w| unsafe { w.bits(mask) });
+        if self.level_mask & mask == 0 {
+            self.pint.ist.write(|w| unsafe { w.bits(mask) });
+        }
+    }
+}
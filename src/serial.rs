@@ -26,8 +26,144 @@ pub struct Serial<PINS> {
     _pins: PINS
 }
 
+/// Number of data bits per USART frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordLength {
+    Five,
+    Six,
+    Seven,
+    Eight,
+}
+
+/// Parity bit configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parity {
+    None,
+    Even,
+    Odd,
+}
+
+/// Number of stop bits per USART frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopBits {
+    One,
+    Two,
+}
+
+/// USART framing configuration, consumed by [`Serial::new`].
+///
+/// Defaults to 8 data bits, no parity, 1 stop bit (8N1).
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub baudrate: crate::clock::Hertz,
+    word_length: WordLength,
+    parity: Parity,
+    stop_bits: StopBits,
+}
+
+impl Config {
+    pub fn new(baudrate: crate::clock::Hertz) -> Self {
+        Config {
+            baudrate,
+            word_length: WordLength::Eight,
+            parity: Parity::None,
+            stop_bits: StopBits::One,
+        }
+    }
+
+    pub fn word_length(mut self, word_length: WordLength) -> Self {
+        self.word_length = word_length;
+        self
+    }
+
+    pub fn parity(mut self, parity: Parity) -> Self {
+        self.parity = parity;
+        self
+    }
+
+    pub fn stop_bits(mut self, stop_bits: StopBits) -> Self {
+        self.stop_bits = stop_bits;
+        self
+    }
+}
+
+impl From<crate::clock::Hertz> for Config {
+    fn from(baudrate: crate::clock::Hertz) -> Self {
+        Config::new(baudrate)
+    }
+}
+
+/// A `(DL, MULVAL, DIVADDVAL)` candidate divisor and the absolute baud-rate
+/// error (in Hz) it produces, used while searching for the best fractional
+/// divider below.
+struct BaudCandidate {
+    dl: u32,
+    mulval: u8,
+    divaddval: u8,
+    error: u32,
+}
+
+/// Searches every `(MULVAL, DIVADDVAL)` fractional-divider pair for the one
+/// minimizing the error against `baudrate`, given a `PCLK` of `clkin`.
+///
+/// The effective baud rate is `PCLK / (16 * DL * (1 + DIVADDVAL / MULVAL))`,
+/// with `DL = 256*DLM + DLL` in `1..=65535`, `1 <= MULVAL <= 15` and
+/// `0 <= DIVADDVAL < MULVAL`. Both sides of the ratio are scaled by `MULVAL`
+/// to keep everything in integer math.
+fn calc_baud_divisors(clkin: u32, baudrate: u32) -> Option<(u32, u8, u8)> {
+    // Start with the fractional divider disabled (MULVAL=1, DIVADDVAL=0),
+    // i.e. a plain integer divisor, as the initial candidate.
+    let mut best: Option<BaudCandidate> = None;
+
+    for mulval in 1..=15u8 {
+        for divaddval in 0..mulval {
+            // FR = 1 + divaddval/mulval, scaled by mulval: FR * mulval = mulval + divaddval
+            let fr_scaled = mulval as u32 + divaddval as u32;
+
+            // DL = round(PCLK / (16 * baudrate * FR))
+            //    = round(PCLK * mulval / (16 * baudrate * fr_scaled))
+            let numerator = clkin as u64 * mulval as u64;
+            let denominator = 16 * baudrate as u64 * fr_scaled as u64;
+            let dl = ((numerator + denominator / 2) / denominator).max(1);
+
+            if dl > 65535 {
+                continue;
+            }
+
+            // actual = PCLK * mulval / (16 * dl * fr_scaled)
+            let actual = numerator / (16 * dl * fr_scaled as u64);
+            let error = if actual > baudrate as u64 {
+                (actual - baudrate as u64) as u32
+            } else {
+                (baudrate as u64 - actual) as u32
+            };
+
+            let candidate = BaudCandidate { dl: dl as u32, mulval, divaddval, error };
+            if best.as_ref().map_or(true, |b| candidate.error < b.error) {
+                best = Some(candidate);
+            }
+        }
+    }
+
+    best.and_then(|b| {
+        // Reject results with more than 3% error.
+        if (b.error as u64 * 100) > (baudrate as u64 * 3) {
+            None
+        } else {
+            Some((b.dl, b.mulval, b.divaddval))
+        }
+    })
+}
+
+/// The requested [`Config::baudrate`] cannot be reached within 3% error at
+/// the current [`Clocks::main_clock_freq`].
+#[derive(Debug)]
+pub struct InvalidBaudRate;
+
 impl<PINS: Pins> Serial<PINS> {
-    pub fn new(usart: USART, pins: PINS, clocks: Clocks, mut usart_clock: USARTClock, baudrate: crate::clock::Hertz) -> Serial<PINS> {
+    pub fn new(usart: USART, pins: PINS, clocks: Clocks, mut usart_clock: USARTClock, config: impl Into<Config>) -> Result<Serial<PINS>, InvalidBaudRate> {
+        let config = config.into();
+
         usart_clock.configure(clocks, clocks.main_clock_freq());
 
         usart.fcr_mut().write(|v| v
@@ -35,32 +171,43 @@ impl<PINS: Pins> Serial<PINS> {
             .rxfifores().clear()
             .txfifores().clear());
 
-        usart.lcr.write(|v| v
-            .wls()._8_bit_character_leng()
-            .sbs()._1_stop_bit()
-            .pe().disabled());
-
-        // Disable fractional divider
-        usart.fdr.write(|v| unsafe { v
-            .divaddval().bits(0)
-            .mulval().bits(1)
+        usart.lcr.write(|v| {
+            let v = match config.word_length {
+                WordLength::Five => v.wls()._5_bit_character_leng(),
+                WordLength::Six => v.wls()._6_bit_character_leng(),
+                WordLength::Seven => v.wls()._7_bit_character_leng(),
+                WordLength::Eight => v.wls()._8_bit_character_leng(),
+            };
+            let v = match config.stop_bits {
+                StopBits::One => v.sbs()._1_stop_bit(),
+                StopBits::Two => v.sbs()._2_stop_bit(),
+            };
+            match config.parity {
+                Parity::None => v.pe().disabled(),
+                Parity::Even => v.pe().enabled().ps().even_parity(),
+                Parity::Odd => v.pe().enabled().ps().odd_parity(),
+            }
         });
 
         let clkin = clocks.main_clock_freq();
-        let div = clkin.0 / (baudrate.0 * 16);
+        let (dl, mulval, divaddval) = calc_baud_divisors(clkin.0, config.baudrate.0)
+            .ok_or(InvalidBaudRate)?;
 
-        assert!(div < (1 << 16), "Baudrate is too damn high!");
+        usart.fdr.write(|v| unsafe { v
+            .divaddval().bits(divaddval)
+            .mulval().bits(mulval)
+        });
 
-        let divh = (div / 256) as u8;
-        let divl = (div % 256) as u8;
+        let divh = (dl / 256) as u8;
+        let divl = (dl % 256) as u8;
 
         usart.lcr.modify(|_, v| v.dlab().enable_access_to_div());
         usart.dll_mut().write(|v| unsafe { v.dllsb().bits(divl) });
         usart.dlm_mut().write(|v| unsafe { v.dlmsb().bits(divh) });
         usart.lcr.modify(|_, v| v.dlab().disable_access_to_di());
-        Serial {
+        Ok(Serial {
             usart, _pins: pins
-        }
+        })
     }
 }
 
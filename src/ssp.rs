@@ -0,0 +1,176 @@
+//! SSP (SPI) Configuration
+//!
+//! The LPC11Uxx exposes two SSP blocks, `SSP0` and `SSP1`, each of which can
+//! be wired up to a set of GPIO pins (see the `SCK*`/`MOSI*`/`MISO*`
+//! marker types in [`crate::gpio`]) and driven as a SPI master. Slave
+//! select is left up to the caller, driven as a plain GPIO output, rather
+//! than bound to the SSP peripheral's own `SSEL` pin.
+
+use core::ops::Deref;
+
+use embedded_hal::blocking::spi::{Transfer, Write};
+pub use embedded_hal::spi::{Mode, Phase, Polarity};
+use embedded_hal::spi::FullDuplex;
+
+use crate::clock::{Clocks, Hertz, SspClock};
+use crate::gpio::{MISO0, MISO1, MOSI0, MOSI1, SCK0, SCK1};
+
+/// Implemented by the SSP peripheral instances (`SSP0`, `SSP1`), giving
+/// [`Spi`] access to the shared SSP register block regardless of instance.
+pub trait Instance: Deref<Target = lpc11uxx::ssp0::RegisterBlock> {}
+
+impl Instance for lpc11uxx::SSP0 {}
+impl Instance for lpc11uxx::SSP1 {}
+
+pub trait PinSck<SSP> {}
+pub trait PinMosi<SSP> {}
+pub trait PinMiso<SSP> {}
+
+impl PinSck<lpc11uxx::SSP0> for crate::gpio::gpio0::Pio6<SCK0> {}
+impl PinSck<lpc11uxx::SSP0> for crate::gpio::gpio0::Pio10<SCK0> {}
+impl PinSck<lpc11uxx::SSP0> for crate::gpio::gpio1::Pio29<SCK0> {}
+impl PinMosi<lpc11uxx::SSP0> for crate::gpio::gpio0::Pio9<MOSI0> {}
+impl PinMiso<lpc11uxx::SSP0> for crate::gpio::gpio0::Pio8<MISO0> {}
+
+impl PinSck<lpc11uxx::SSP1> for crate::gpio::gpio1::Pio15<SCK1> {}
+impl PinSck<lpc11uxx::SSP1> for crate::gpio::gpio1::Pio20<SCK1> {}
+impl PinMosi<lpc11uxx::SSP1> for crate::gpio::gpio0::Pio21<MOSI1> {}
+impl PinMosi<lpc11uxx::SSP1> for crate::gpio::gpio1::Pio22<MOSI1> {}
+impl PinMiso<lpc11uxx::SSP1> for crate::gpio::gpio0::Pio22<MISO1> {}
+impl PinMiso<lpc11uxx::SSP1> for crate::gpio::gpio1::Pio21<MISO1> {}
+
+pub trait Pins<SSP> {}
+
+impl<SSP, SCK, MOSI, MISO> Pins<SSP> for (SCK, MOSI, MISO)
+where
+    SCK: PinSck<SSP>,
+    MOSI: PinMosi<SSP>,
+    MISO: PinMiso<SSP>,
+{}
+
+// TODO: BITFLAGS
+#[derive(Debug)]
+pub struct Error {
+    sr: u32,
+}
+
+pub struct Spi<SSP, PINS> {
+    ssp: SSP,
+    pins: PINS,
+}
+
+/// Computes the `(CPSR, SCR)` pair driving the SSP bus as close as possible
+/// to, but not above, `freq`, given a `pclk` peripheral clock.
+///
+/// The SSP bit rate is `pclk / (CPSR * (SCR + 1))`, with `CPSR` an even
+/// value in `2..=254` and `SCR` in `0..=255`.
+fn calc_prescale(pclk: u32, freq: u32) -> (u8, u8) {
+    let mut cpsr = 2u32;
+    while cpsr <= 254 {
+        // Ceiling division: a divisor that rounds up never drives the
+        // actual rate above `freq`, whereas a floored divisor can.
+        let divisor = cpsr * freq;
+        let scr = (pclk + divisor - 1) / divisor;
+        if scr >= 1 && scr <= 256 {
+            return (cpsr as u8, (scr - 1) as u8);
+        }
+        cpsr += 2;
+    }
+    (254, 255)
+}
+
+impl<SSP: Instance, PINS: Pins<SSP>> Spi<SSP, PINS> {
+    pub fn new<CLK: SspClock>(
+        ssp: SSP,
+        pins: PINS,
+        mode: Mode,
+        freq: Hertz,
+        clocks: Clocks,
+        mut ssp_clock: CLK,
+    ) -> Self {
+        // Leave the SSP peripheral clock divider as a no-op (divide by 1,
+        // as `Serial::new` does with `USARTClock`) and do all the real
+        // division via CPSR/SCR below, so the two don't compound.
+        ssp_clock.configure(clocks, clocks.main_clock_freq());
+
+        let (cpsr, scr) = calc_prescale(clocks.main_clock_freq().0, freq.0);
+
+        ssp.cr1.write(|v| v.sse().disabled());
+
+        ssp.cr0.write(|v| unsafe {
+            v
+                .dss().bits(7) // 8 bit data, the embedded-hal default.
+                .frf().spi()
+                .cpol().bit(mode.polarity == Polarity::IdleHigh)
+                .cpha().bit(mode.phase == Phase::CaptureOnSecondTransition)
+                .scr().bits(scr)
+        });
+
+        ssp.cpsr.write(|v| unsafe { v.cpsdvsr().bits(cpsr) });
+
+        ssp.cr1.write(|v| v.sse().enabled());
+
+        Spi { ssp, pins }
+    }
+
+    /// Reconfigures the number of bits per SPI frame, from 4 to 16.
+    pub fn set_data_size(&mut self, bits: u8) {
+        assert!(bits >= 4 && bits <= 16, "SSP data size must be between 4 and 16 bits");
+        self.ssp.cr0.modify(|_, v| unsafe { v.dss().bits(bits - 1) });
+    }
+
+    pub fn free(self) -> (SSP, PINS) {
+        (self.ssp, self.pins)
+    }
+}
+
+impl<SSP: Instance, PINS> FullDuplex<u8> for Spi<SSP, PINS> {
+    type Error = Error;
+
+    fn read(&mut self) -> nb::Result<u8, Error> {
+        let sr = self.ssp.sr.read();
+
+        if sr.rne().is_valid() {
+            Ok(self.ssp.dr.read().data().bits() as u8)
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    fn send(&mut self, byte: u8) -> nb::Result<(), Error> {
+        let sr = self.ssp.sr.read();
+
+        if sr.tnf().is_valid() {
+            self.ssp.dr.write(|v| unsafe { v.data().bits(byte as u16) });
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+impl<SSP: Instance, PINS> Transfer<u8> for Spi<SSP, PINS> {
+    type Error = Error;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Error> {
+        for word in words.iter_mut() {
+            nb::block!(self.send(*word))?;
+            *word = nb::block!(self.read())?;
+        }
+
+        Ok(words)
+    }
+}
+
+impl<SSP: Instance, PINS> Write<u8> for Spi<SSP, PINS> {
+    type Error = Error;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Error> {
+        for word in words {
+            nb::block!(self.send(*word))?;
+            nb::block!(self.read())?;
+        }
+
+        Ok(())
+    }
+}